@@ -5,17 +5,51 @@ use deno_core::BufVec;
 use deno_core::ErrBox;
 use deno_core::OpRegistry;
 use deno_core::ZeroCopyBuf;
+use serde_derive::Deserialize;
 use serde_json::Value;
 use std::rc::Rc;
 
-#[cfg(unix)]
+#[cfg(any(unix, windows))]
 use futures::future::poll_fn;
+#[cfg(any(unix, windows))]
+use std::task::Waker;
+
 #[cfg(unix)]
-use serde_derive::Deserialize;
+use lazy_static::lazy_static;
 #[cfg(unix)]
-use std::task::Waker;
+use std::collections::HashMap;
+#[cfg(unix)]
+use std::sync::atomic::AtomicU64;
+#[cfg(unix)]
+use std::sync::atomic::Ordering;
+#[cfg(unix)]
+use std::sync::Arc;
+#[cfg(unix)]
+use std::sync::Mutex;
 #[cfg(unix)]
 use tokio::signal::unix::{signal, Signal, SignalKind};
+#[cfg(unix)]
+use tokio::sync::Notify;
+
+#[cfg(windows)]
+use futures::channel::mpsc;
+#[cfg(windows)]
+use futures::StreamExt;
+#[cfg(windows)]
+use lazy_static::lazy_static;
+#[cfg(windows)]
+use std::collections::HashMap;
+#[cfg(windows)]
+use std::sync::Mutex;
+#[cfg(windows)]
+use winapi::shared::minwindef::{BOOL, DWORD, FALSE, TRUE};
+#[cfg(windows)]
+use winapi::um::consoleapi::SetConsoleCtrlHandler;
+#[cfg(windows)]
+use winapi::um::wincon::{
+  CTRL_BREAK_EVENT, CTRL_CLOSE_EVENT, CTRL_C_EVENT, CTRL_LOGOFF_EVENT,
+  CTRL_SHUTDOWN_EVENT,
+};
 
 pub fn init(s: &Rc<State>) {
   s.register_op_json_sync("op_signal_bind", op_signal_bind);
@@ -23,23 +57,332 @@ pub fn init(s: &Rc<State>) {
   s.register_op_json_async("op_signal_poll", op_signal_poll);
 }
 
-#[cfg(unix)]
-/// The resource for signal stream.
-/// The second element is the waker of polling future.
-pub struct SignalStreamResource(pub Signal, pub Option<Waker>);
-
-#[cfg(unix)]
 #[derive(Deserialize)]
 struct BindSignalArgs {
-  signo: i32,
+  signo: Option<i32>,
+  signal: Option<String>,
 }
 
-#[cfg(unix)]
 #[derive(Deserialize)]
 struct SignalArgs {
   rid: i32,
 }
 
+/// Not a real signal number (every POSIX signal number is positive): a
+/// sentinel for Windows' Ctrl-Break console event, which has no POSIX
+/// equivalent and so can't share the real signal number space without
+/// risking collision with a real-time signal (e.g. Linux's SIGRTMIN is
+/// 32).
+const SIGBREAK_SIGNO: i32 = -1;
+
+/// Known signal names, shared across platforms so `Deno.signal("SIGINT")`
+/// means the same thing everywhere. Numbers here are platform-specific:
+/// BSD-family unixes (including macOS) number several signals
+/// differently than Linux does, so `SIGNAL_NAMES` is one of three
+/// per-platform tables below rather than one hardcoded layout.
+#[cfg(all(
+  unix,
+  any(
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "openbsd",
+    target_os = "netbsd",
+    target_os = "dragonfly"
+  )
+))]
+const SIGNAL_NAMES: &[(&str, i32)] = &[
+  ("SIGHUP", 1),
+  ("SIGINT", 2),
+  ("SIGQUIT", 3),
+  ("SIGILL", 4),
+  ("SIGTRAP", 5),
+  ("SIGABRT", 6),
+  ("SIGEMT", 7),
+  ("SIGFPE", 8),
+  ("SIGKILL", 9),
+  ("SIGBUS", 10),
+  ("SIGSEGV", 11),
+  ("SIGSYS", 12),
+  ("SIGPIPE", 13),
+  ("SIGALRM", 14),
+  ("SIGTERM", 15),
+  ("SIGURG", 16),
+  ("SIGSTOP", 17),
+  ("SIGTSTP", 18),
+  ("SIGCONT", 19),
+  ("SIGCHLD", 20),
+  ("SIGTTIN", 21),
+  ("SIGTTOU", 22),
+  ("SIGIO", 23),
+  ("SIGXCPU", 24),
+  ("SIGXFSZ", 25),
+  ("SIGVTALRM", 26),
+  ("SIGPROF", 27),
+  ("SIGWINCH", 28),
+  ("SIGINFO", 29),
+  ("SIGUSR1", 30),
+  ("SIGUSR2", 31),
+];
+
+#[cfg(all(
+  unix,
+  not(any(
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "openbsd",
+    target_os = "netbsd",
+    target_os = "dragonfly"
+  ))
+))]
+const SIGNAL_NAMES: &[(&str, i32)] = &[
+  ("SIGHUP", 1),
+  ("SIGINT", 2),
+  ("SIGQUIT", 3),
+  ("SIGILL", 4),
+  ("SIGTRAP", 5),
+  ("SIGABRT", 6),
+  ("SIGBUS", 7),
+  ("SIGFPE", 8),
+  ("SIGKILL", 9),
+  ("SIGUSR1", 10),
+  ("SIGSEGV", 11),
+  ("SIGUSR2", 12),
+  ("SIGPIPE", 13),
+  ("SIGALRM", 14),
+  ("SIGTERM", 15),
+  ("SIGSTKFLT", 16),
+  ("SIGCHLD", 17),
+  ("SIGCONT", 18),
+  ("SIGSTOP", 19),
+  ("SIGTSTP", 20),
+  ("SIGTTIN", 21),
+  ("SIGTTOU", 22),
+  ("SIGURG", 23),
+  ("SIGXCPU", 24),
+  ("SIGXFSZ", 25),
+  ("SIGVTALRM", 26),
+  ("SIGPROF", 27),
+  ("SIGWINCH", 28),
+  ("SIGIO", 29),
+  ("SIGPWR", 30),
+  ("SIGSYS", 31),
+];
+
+#[cfg(windows)]
+const SIGNAL_NAMES: &[(&str, i32)] = &[
+  ("SIGHUP", 1),
+  ("SIGINT", 2),
+  ("SIGQUIT", 3),
+  ("SIGTERM", 15),
+  ("SIGBREAK", SIGBREAK_SIGNO),
+];
+
+/// Signal names that binding is refused for on every platform,
+/// looked up through the active `SIGNAL_NAMES` table rather than
+/// hardcoded as numbers, since those numbers differ per platform.
+/// Rejecting a bind to one of these up front turns what used to be a
+/// panic in `op_signal_bind` into a catchable `TypeError`; this matches
+/// the set tokio/`signal_hook` refuse: the two signals that are truly
+/// uncatchable (`SIGKILL`, `SIGSTOP`) plus the synchronous-fault signals
+/// whose handlers can't usefully resume (`SIGILL`, `SIGFPE`, `SIGSEGV`,
+/// `SIGBUS`).
+const UNCATCHABLE_SIGNAL_NAMES: &[&str] =
+  &["SIGKILL", "SIGSTOP", "SIGILL", "SIGFPE", "SIGSEGV", "SIGBUS"];
+
+fn is_uncatchable(signo: i32) -> bool {
+  UNCATCHABLE_SIGNAL_NAMES.iter().any(|name| {
+    SIGNAL_NAMES
+      .iter()
+      .any(|(known, known_signo)| known == name && *known_signo == signo)
+  })
+}
+
+/// Resolves a `BindSignalArgs` down to a single validated signal number,
+/// accepting either a raw `signo` or a portable name like `"SIGINT"`.
+fn resolve_signo(args: &BindSignalArgs) -> Result<i32, ErrBox> {
+  let signo = match (&args.signal, args.signo) {
+    (Some(name), _) => SIGNAL_NAMES
+      .iter()
+      .find(|(known, _)| known.eq_ignore_ascii_case(name))
+      .map(|(_, signo)| *signo)
+      .ok_or_else(|| {
+        ErrBox::new("TypeError", format!("Unknown signal name '{}'", name))
+      })?,
+    (None, Some(signo)) => signo,
+    (None, None) => {
+      return Err(ErrBox::new(
+        "TypeError",
+        "Either 'signo' or 'signal' must be specified",
+      ))
+    }
+  };
+
+  // No upper bound: real-time signals (e.g. Linux's SIGRTMIN..SIGRTMAX,
+  // all above 31) are valid and the baseline's bare
+  // `SignalKind::from_raw` accepted them, so keep accepting them here.
+  if signo != SIGBREAK_SIGNO && signo < 1 {
+    return Err(ErrBox::new(
+      "TypeError",
+      format!("Invalid signal number '{}'", signo),
+    ));
+  }
+  if is_uncatchable(signo) {
+    return Err(ErrBox::new(
+      "TypeError",
+      format!("Binding to signal '{}' is not allowed", signo),
+    ));
+  }
+
+  Ok(signo)
+}
+
+#[cfg(unix)]
+lazy_static! {
+  // Deliberately process-global rather than an `Rc<RefCell<_>>` on
+  // `State`: a POSIX signal is delivered to the process as a whole, not
+  // to a particular isolate, so "is anyone still listening for SIGINT"
+  // and "should the OS subscription be torn down" are process-wide
+  // questions. Scoping this table to one isolate's `State` wouldn't
+  // actually stop separate isolates/workers from interfering with each
+  // other's subscription to the same signal number — they'd still be
+  // racing to install/remove the same underlying OS-level handler, just
+  // through two disconnected bookkeeping tables instead of one shared
+  // one, which is worse. Everything that *is* naturally per-isolate
+  // (which `SignalStreamResource` belongs to which rid, its own
+  // last-seen counter value) still lives on that isolate's resource
+  // table, as usual; only the cross-cutting state for the shared OS
+  // subscription (its refcount, fire counter and parked wakers) lives
+  // here.
+  //
+  /// Per-signal-number counters of how many times each signal has fired
+  /// since its current OS subscription was set up (the counter is dropped
+  /// when the last listener unbinds). Shared by every `SignalStreamResource`
+  /// watching that signal number so a burst that arrives while nothing
+  /// happens to be polling is never lost: each resource just diffs
+  /// against the last value it saw.
+  static ref SIGNAL_COUNTERS: Mutex<HashMap<i32, Arc<AtomicU64>>> =
+    Mutex::new(HashMap::new());
+  /// Wakers parked by a pending `op_signal_poll`, keyed by signal number.
+  static ref SIGNAL_WAKERS: Mutex<HashMap<i32, Vec<Waker>>> =
+    Mutex::new(HashMap::new());
+  /// Number of `SignalStreamResource`s currently bound to each signal
+  /// number, plus the handle used to stop that signal's delivery task.
+  /// This is what lets several resources share the one OS-level
+  /// subscription for a signal: the task is spawned when a signo's count
+  /// goes from zero to one, and stopped when it drops back to zero.
+  static ref SIGNAL_LISTENERS: Mutex<HashMap<i32, (usize, Arc<Notify>)>> =
+    Mutex::new(HashMap::new());
+}
+
+#[cfg(unix)]
+/// Registers a new listener for `signo`, fanning out to the one
+/// underlying OS subscription: a delivery task is spawned only for the
+/// first listener, every later one just shares the same counter.
+///
+/// The OS-level subscription is established here, synchronously, rather
+/// than inside the spawned delivery task: `signal()` can fail (e.g. if
+/// the process has run out of signal slots), and a caller-visible error
+/// from a failed `op_signal_bind` is the only way JS ever finds out —
+/// once the delivery task is spawned and detached, nothing is watching
+/// it for errors.
+fn register_listener(signo: i32) -> Result<Arc<AtomicU64>, ErrBox> {
+  let mut listeners = SIGNAL_LISTENERS.lock().unwrap();
+  if let Some((count, _)) = listeners.get_mut(&signo) {
+    *count += 1;
+    return Ok(
+      SIGNAL_COUNTERS
+        .lock()
+        .unwrap()
+        .entry(signo)
+        .or_insert_with(|| Arc::new(AtomicU64::new(0)))
+        .clone(),
+    );
+  }
+
+  let os_signal = signal(SignalKind::from_raw(signo))
+    .map_err(|e| ErrBox::new("Error", format!("{}", e)))?;
+  let counter = SIGNAL_COUNTERS
+    .lock()
+    .unwrap()
+    .entry(signo)
+    .or_insert_with(|| Arc::new(AtomicU64::new(0)))
+    .clone();
+  let shutdown = Arc::new(Notify::new());
+  listeners.insert(signo, (1, shutdown.clone()));
+  spawn_signal_delivery_task(signo, os_signal, counter.clone(), shutdown);
+  Ok(counter)
+}
+
+#[cfg(unix)]
+/// Drives one signal number's OS-level subscription. All of the work
+/// that isn't safe to do inside an actual signal handler (taking these
+/// mutexes, calling `Waker::wake`) happens here instead, in an ordinary
+/// tokio task woken through tokio's own self-pipe-based signal
+/// integration.
+fn spawn_signal_delivery_task(
+  signo: i32,
+  mut os_signal: Signal,
+  counter: Arc<AtomicU64>,
+  shutdown: Arc<Notify>,
+) {
+  tokio::task::spawn_local(async move {
+    loop {
+      tokio::select! {
+        received = os_signal.recv() => {
+          if received.is_none() {
+            break;
+          }
+          counter.fetch_add(1, Ordering::Relaxed);
+          if let Ok(mut wakers) = SIGNAL_WAKERS.lock() {
+            if let Some(parked) = wakers.get_mut(&signo) {
+              for waker in parked.drain(..) {
+                waker.wake();
+              }
+            }
+          }
+        }
+        _ = shutdown.notified() => break,
+      }
+    }
+  });
+}
+
+#[cfg(unix)]
+/// Removes one listener for `signo`, stopping its delivery task and
+/// resetting its counter once the last listener goes away.
+fn unregister_listener(signo: i32) {
+  let mut listeners = SIGNAL_LISTENERS.lock().unwrap();
+  let shutdown = match listeners.get_mut(&signo) {
+    Some((count, _)) => {
+      *count -= 1;
+      if *count == 0 {
+        listeners.remove(&signo).map(|(_, shutdown)| shutdown)
+      } else {
+        None
+      }
+    }
+    None => None,
+  };
+  if let Some(shutdown) = shutdown {
+    shutdown.notify_one();
+    SIGNAL_COUNTERS.lock().unwrap().remove(&signo);
+    SIGNAL_WAKERS.lock().unwrap().remove(&signo);
+  }
+}
+
+#[cfg(unix)]
+/// The resource for a signal stream. Tracks the last counter value this
+/// resource has observed so `op_signal_poll` can report how many signals
+/// fired since the previous poll.
+pub struct SignalStreamResource {
+  signo: i32,
+  counter: Arc<AtomicU64>,
+  last_seen: u64,
+  waker: Option<Waker>,
+}
+
 #[cfg(unix)]
 fn op_signal_bind(
   state: &State,
@@ -48,12 +391,17 @@ fn op_signal_bind(
 ) -> Result<Value, ErrBox> {
   state.check_unstable("Deno.signal");
   let args: BindSignalArgs = serde_json::from_value(args)?;
+  let signo = resolve_signo(&args)?;
+  let counter = register_listener(signo)?;
+  let last_seen = counter.load(Ordering::Relaxed);
   let rid = state.resource_table.borrow_mut().add(
     "signal",
-    Box::new(SignalStreamResource(
-      signal(SignalKind::from_raw(args.signo)).expect(""),
-      None,
-    )),
+    Box::new(SignalStreamResource {
+      signo,
+      counter,
+      last_seen,
+      waker: None,
+    }),
   );
   Ok(json!({
     "rid": rid,
@@ -72,16 +420,38 @@ async fn op_signal_poll(
 
   let future = poll_fn(move |cx| {
     let mut resource_table = state.resource_table.borrow_mut();
-    if let Some(mut signal) =
-      resource_table.get_mut::<SignalStreamResource>(rid)
-    {
-      signal.1 = Some(cx.waker().clone());
-      return signal.0.poll_recv(cx);
+    let signal = match resource_table.get_mut::<SignalStreamResource>(rid) {
+      Some(signal) => signal,
+      None => return std::task::Poll::Ready(None),
+    };
+
+    // Register the waker *before* re-checking the counter. If we checked
+    // first, a signal landing in the gap between the check and the
+    // registration would bump the counter and drain an (still empty)
+    // waker list, and this poll would park having missed it. Registering
+    // first means that race instead just leaves a now-redundant waker
+    // sitting in the list, which is harmless to wake later.
+    signal.waker = Some(cx.waker().clone());
+    SIGNAL_WAKERS
+      .lock()
+      .unwrap()
+      .entry(signal.signo)
+      .or_insert_with(Vec::new)
+      .push(cx.waker().clone());
+
+    let current = signal.counter.load(Ordering::Relaxed);
+    let delta = current.wrapping_sub(signal.last_seen);
+    if delta != 0 {
+      signal.last_seen = current;
+      return std::task::Poll::Ready(Some(delta));
     }
-    std::task::Poll::Ready(None)
+    std::task::Poll::Pending
   });
   let result = future.await;
-  Ok(json!({ "done": result.is_none() }))
+  Ok(json!({
+    "done": result.is_none(),
+    "count": result.unwrap_or(0),
+  }))
 }
 
 #[cfg(unix)]
@@ -95,20 +465,218 @@ pub fn op_signal_unbind(
   let args: SignalArgs = serde_json::from_value(args)?;
   let rid = args.rid as u32;
   let resource = resource_table.get_mut::<SignalStreamResource>(rid);
+  let signo = resource.as_ref().map(|signal| signal.signo);
   if let Some(signal) = resource {
-    if let Some(waker) = &signal.1 {
+    if let Some(waker) = signal.waker.take() {
       // Wakes up the pending poll if exists.
       // This prevents the poll future from getting stuck forever.
-      waker.clone().wake();
+      waker.wake();
     }
   }
   resource_table
     .close(rid)
     .ok_or_else(ErrBox::bad_resource_id)?;
+  if let Some(signo) = signo {
+    unregister_listener(signo);
+  }
+  Ok(json!({}))
+}
+
+#[cfg(windows)]
+/// Maps a `Deno.signal` identifier to the Windows console control event it
+/// corresponds to. Identifiers are the same signal numbers accepted on
+/// unix, reused here so JS code doesn't need to branch on platform.
+fn signo_to_ctrl_type(signo: i32) -> Option<DWORD> {
+  match signo {
+    2 => Some(CTRL_C_EVENT),        // SIGINT
+    3 => Some(CTRL_LOGOFF_EVENT),   // SIGQUIT
+    1 => Some(CTRL_CLOSE_EVENT),    // SIGHUP
+    15 => Some(CTRL_SHUTDOWN_EVENT), // SIGTERM
+    SIGBREAK_SIGNO => Some(CTRL_BREAK_EVENT), // SIGBREAK
+    _ => None,
+  }
+}
+
+#[cfg(windows)]
+lazy_static! {
+  /// One entry per bound resource, keyed by its resource id, so
+  /// `op_signal_unbind` can drop exactly the sender it owns. The console
+  /// control handler itself only ever sees this table, never individual
+  /// resources.
+  static ref WINDOWS_SENDERS: Mutex<HashMap<u32, mpsc::UnboundedSender<DWORD>>> =
+    Mutex::new(HashMap::new());
+}
+
+#[cfg(windows)]
+unsafe extern "system" fn console_ctrl_handler(ctrl_type: DWORD) -> BOOL {
+  match WINDOWS_SENDERS.lock() {
+    // Only claim to have handled the event if something is actually
+    // listening; otherwise fall through to the default handling (e.g. so
+    // Ctrl-C still terminates the process once nothing is bound).
+    Ok(senders) if !senders.is_empty() => {
+      for sender in senders.values() {
+        let _ = sender.unbounded_send(ctrl_type);
+      }
+      TRUE
+    }
+    _ => FALSE,
+  }
+}
+
+#[cfg(windows)]
+/// The resource for a Windows console control-event stream.
+pub struct SignalStreamResource {
+  ctrl_type: DWORD,
+  receiver: mpsc::UnboundedReceiver<DWORD>,
+  waker: Option<Waker>,
+}
+
+#[cfg(windows)]
+impl SignalStreamResource {
+  /// Drains every currently-buffered matching ctrl event non-blockingly
+  /// and reports how many fired, mirroring the unix poll's `count` so a
+  /// burst of presses between two polls isn't silently collapsed to one.
+  fn poll_recv(
+    &mut self,
+    cx: &mut std::task::Context,
+  ) -> std::task::Poll<Option<u64>> {
+    let mut count: u64 = 0;
+    loop {
+      return match self.receiver.poll_next_unpin(cx) {
+        std::task::Poll::Ready(Some(ctrl_type)) => {
+          if ctrl_type == self.ctrl_type {
+            count += 1;
+          }
+          // Keep draining, whether or not this event matched.
+          continue;
+        }
+        std::task::Poll::Ready(None) => {
+          if count != 0 {
+            std::task::Poll::Ready(Some(count))
+          } else {
+            std::task::Poll::Ready(None)
+          }
+        }
+        std::task::Poll::Pending => {
+          if count != 0 {
+            std::task::Poll::Ready(Some(count))
+          } else {
+            std::task::Poll::Pending
+          }
+        }
+      };
+    }
+  }
+}
+
+#[cfg(windows)]
+fn op_signal_bind(
+  state: &State,
+  args: Value,
+  _zero_copy: &mut [ZeroCopyBuf],
+) -> Result<Value, ErrBox> {
+  state.check_unstable("Deno.signal");
+  let args: BindSignalArgs = serde_json::from_value(args)?;
+  let signo = resolve_signo(&args)?;
+  let ctrl_type = signo_to_ctrl_type(signo).ok_or_else(|| {
+    ErrBox::new(
+      "TypeError",
+      format!("Binding to signal '{}' is not supported on Windows", signo),
+    )
+  })?;
+
+  let (sender, receiver) = mpsc::unbounded();
+  let rid = state.resource_table.borrow_mut().add(
+    "signal",
+    Box::new(SignalStreamResource {
+      ctrl_type,
+      receiver,
+      waker: None,
+    }),
+  );
+
+  let mut senders = WINDOWS_SENDERS.lock().unwrap();
+  if senders.is_empty() {
+    // SAFETY: installs a single process-wide console control handler that
+    // fans incoming ctrl events out to every bound `SignalStreamResource`.
+    let installed =
+      unsafe { SetConsoleCtrlHandler(Some(console_ctrl_handler), TRUE) };
+    if installed == FALSE {
+      return Err(ErrBox::new(
+        "Error",
+        "failed to register Windows console control handler",
+      ));
+    }
+  }
+  senders.insert(rid, sender);
+
+  Ok(json!({
+    "rid": rid,
+  }))
+}
+
+#[cfg(windows)]
+async fn op_signal_poll(
+  state: Rc<State>,
+  args: Value,
+  _zero_copy: BufVec,
+) -> Result<Value, ErrBox> {
+  state.check_unstable("Deno.signal");
+  let args: SignalArgs = serde_json::from_value(args)?;
+  let rid = args.rid as u32;
+
+  let future = poll_fn(move |cx| {
+    let mut resource_table = state.resource_table.borrow_mut();
+    if let Some(signal) =
+      resource_table.get_mut::<SignalStreamResource>(rid)
+    {
+      signal.waker = Some(cx.waker().clone());
+      return signal.poll_recv(cx);
+    }
+    std::task::Poll::Ready(None)
+  });
+  let result = future.await;
+  Ok(json!({
+    "done": result.is_none(),
+    "count": result.unwrap_or(0),
+  }))
+}
+
+#[cfg(windows)]
+pub fn op_signal_unbind(
+  state: &State,
+  args: Value,
+  _zero_copy: &mut [ZeroCopyBuf],
+) -> Result<Value, ErrBox> {
+  state.check_unstable("Deno.signal");
+  let args: SignalArgs = serde_json::from_value(args)?;
+  let rid = args.rid as u32;
+
+  let mut resource_table = state.resource_table.borrow_mut();
+  if let Some(signal) = resource_table.get_mut::<SignalStreamResource>(rid) {
+    if let Some(waker) = signal.waker.take() {
+      // Wakes up the pending poll if exists.
+      // This prevents the poll future from getting stuck forever.
+      waker.wake();
+    }
+  }
+  resource_table
+    .close(rid)
+    .ok_or_else(ErrBox::bad_resource_id)?;
+
+  let mut senders = WINDOWS_SENDERS.lock().unwrap();
+  senders.remove(&rid);
+  if senders.is_empty() {
+    // SAFETY: no bound resources remain, so it's safe (and necessary) to
+    // uninstall the handler and restore the default console behavior.
+    unsafe {
+      SetConsoleCtrlHandler(Some(console_ctrl_handler), FALSE);
+    }
+  }
   Ok(json!({}))
 }
 
-#[cfg(not(unix))]
+#[cfg(not(any(unix, windows)))]
 pub fn op_signal_bind(
   _state: &State,
   _args: Value,
@@ -117,7 +685,7 @@ pub fn op_signal_bind(
   unimplemented!();
 }
 
-#[cfg(not(unix))]
+#[cfg(not(any(unix, windows)))]
 fn op_signal_unbind(
   _state: &State,
   _args: Value,
@@ -126,7 +694,7 @@ fn op_signal_unbind(
   unimplemented!();
 }
 
-#[cfg(not(unix))]
+#[cfg(not(any(unix, windows)))]
 async fn op_signal_poll(
   _state: Rc<State>,
   _args: Value,